@@ -1,13 +1,29 @@
 //! This is a minimal library implementing global, thread-safe counters.
+//!
+//! Enabling the `no_std` cfg (set by the crate's `no_std` feature, the same way the
+//! `parking_lot` feature sets the `parking_lot` cfg) builds this crate, `generic::Counter`
+//! included, against `core` and `spin` instead of `std`.
 
+#![cfg_attr(no_std, no_std)]
+
+// The test harness always links `std`, `no_std` lib or not.
+#[cfg(all(no_std, test))]
+extern crate std;
+
+#[cfg(not(no_std))]
 extern crate lazy_static;
 
 // We need to pub use lazy_static, as global_(default_)counter! is expanded to a lazy_static! call.
 // Absolute paths wont help here.
 // TODO: Think of a way to only pub reexport the lazy_static! macro.
+#[cfg(not(no_std))]
 #[doc(hidden)]
 pub use lazy_static::*;
 
+#[cfg(no_std)]
+#[doc(hidden)]
+pub use spin;
+
 // Hack for macro export.
 // In foreign crates, `global_counter::generic::Counter` will be the name of our counter,
 // but in this crate (for testing), we need to artificially introduce this path.
@@ -19,15 +35,56 @@ pub mod global_counter {
     }
 }
 
+/// A trait unifying the counters of this crate, so code can be generic over "a counter"
+/// instead of hand-picking one concrete counter type.
+///
+/// Implemented for every counter in this crate (when its counted type supports it, for the
+/// generic ones): every primitive counter, [FlushingCounter](primitive/struct.FlushingCounter.html),
+/// [ApproxCounter](primitive/struct.ApproxCounter.html), [EventuallyConsistentCounter](primitive/struct.EventuallyConsistentCounter.html),
+/// [StripedCounter](primitive/struct.StripedCounter.html), [ShardedCounter](primitive/struct.ShardedCounter.html),
+/// [WaitableCounter](primitive/struct.WaitableCounter.html), [generic::Counter](generic/struct.Counter.html),
+/// [generic::AtomicCounter](generic/struct.AtomicCounter.html), [generic::LocklessCounter](generic/struct.LocklessCounter.html)
+/// and [generic::ShardedRwCounter](generic/struct.ShardedRwCounter.html). This mirrors the design
+/// of the `atomic-counter` crate.
+///
+/// `add` gives every counter a bulk-increment, which is far cheaper than looping `inc` N times:
+/// a single `fetch_add(n)` on the primitives, and a single thread-local add on the local
+/// (flushing/approximate) counters.
+pub trait Counting {
+    /// The type returned by this counter's operations.
+    type Output;
+
+    /// Increments the counter by one.
+    fn inc(&self) -> Self::Output;
+
+    /// Increments the counter by `n`. Cheaper than calling `inc` `n` times.
+    fn add(&self, n: Self::Output) -> Self::Output;
+
+    /// Gets the current value of the counter.
+    fn get(&self) -> Self::Output;
+
+    /// Resets the counter.
+    fn reset(&self);
+}
+
 /// This module contains atomic counters for primitive integer types.
 pub mod primitive {
+    #[cfg(not(no_std))]
     use std::cell::UnsafeCell;
+    #[cfg(not(no_std))]
     use std::sync::atomic::{
         AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicU16, AtomicU32, AtomicU64,
         AtomicU8, AtomicUsize, Ordering,
     };
+    #[cfg(not(no_std))]
     use std::thread::LocalKey;
 
+    #[cfg(no_std)]
+    use core::sync::atomic::{
+        AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicU16, AtomicU32, AtomicU64,
+        AtomicU8, AtomicUsize, Ordering,
+    };
+
     /// A flushing counter.
     /// 
     /// This counter is intended to be used in one specific way: 
@@ -36,6 +93,7 @@ pub mod primitive {
     /// then, after every flush is guaranteed to have been executed, `get` will return the exact amount of times `inc` has been called (+ the starting offset).
     /// 
     /// In theory, this counter is equivalent to an approximate counter with its resolution set to infinity.
+    #[cfg(not(no_std))]
     pub struct FlushingCounter {
         global_counter: AtomicUsize,
 
@@ -44,6 +102,7 @@ pub mod primitive {
         thread_local_counter: &'static LocalKey<UnsafeCell<usize>>,
     }
 
+    #[cfg(not(no_std))]
     impl FlushingCounter{
         /// Creates a new counter, with the given starting value. Can be used in static contexts.
         #[inline]
@@ -84,6 +143,41 @@ pub mod primitive {
         }
     }
 
+    #[cfg(not(no_std))]
+    impl crate::Counting for FlushingCounter {
+        type Output = usize;
+
+        /// Increments the local counter by one, then returns `get`. Note that, per the
+        /// struct-level documentation, this is only the exact total once every thread has
+        /// `flush`ed.
+        #[inline]
+        fn inc(&self) -> usize {
+            self.inc();
+            self.get()
+        }
+
+        /// Adds `n` to the local counter directly, rather than looping `inc` `n` times.
+        #[inline]
+        fn add(&self, n: usize) -> usize {
+            self.thread_local_counter.with(|tlc| unsafe {
+                *tlc.get() += n;
+            });
+            self.get()
+        }
+
+        #[inline]
+        fn get(&self) -> usize {
+            self.get()
+        }
+
+        /// Resets the global counter to zero. Does not reset any thread's already-accumulated
+        /// local counter, same as the rest of this type's "flush to see the real value" contract.
+        #[inline]
+        fn reset(&self) {
+            self.global_counter.store(0, Ordering::Relaxed);
+        }
+    }
+
     /// An approximate counter.
     ///
     /// This counter operates by having a local counter for each thread, which is occasionally flushed to the main global counter.
@@ -105,6 +199,7 @@ pub mod primitive {
     ///
     /// This counter is ony available for usize, if you need other types drop by the repo and open an issue.
     /// I wasn't able to think of a reason why somebody would want to approximately count using i8s.
+    #[cfg(not(no_std))]
     pub struct ApproxCounter {
         threshold: usize,
         global_counter: AtomicUsize,
@@ -114,6 +209,7 @@ pub mod primitive {
         thread_local_counter: &'static LocalKey<UnsafeCell<usize>>,
     }
 
+    #[cfg(not(no_std))]
     impl ApproxCounter {
         // TODO: Evaluate which atomic ordering is the minimum upholding all these guarantees.
         // Proof needed, altough relaxed seems to pass all tests.
@@ -173,6 +269,137 @@ pub mod primitive {
         }
     }
 
+    #[cfg(not(no_std))]
+    impl crate::Counting for ApproxCounter {
+        type Output = usize;
+
+        /// Increments the local counter by one (possibly flushing it, per the struct-level
+        /// documentation), then returns `get`.
+        #[inline]
+        fn inc(&self) -> usize {
+            self.inc();
+            self.get()
+        }
+
+        /// Adds `n` to the local counter directly, rather than looping `inc` `n` times. Also
+        /// subject to the threshold: if the local total reaches `resolution`, it is flushed.
+        #[inline]
+        fn add(&self, n: usize) -> usize {
+            self.thread_local_counter.with(|tlc| unsafe {
+                let tlc = &mut *tlc.get();
+                *tlc += n;
+                if *tlc >= self.threshold {
+                    self.global_counter.fetch_add(*tlc, Ordering::SeqCst);
+                    *tlc = 0;
+                }
+            });
+            self.get()
+        }
+
+        #[inline]
+        fn get(&self) -> usize {
+            self.get()
+        }
+
+        /// Resets the global counter to zero. As with `FlushingCounter::reset`, any thread's
+        /// already-accumulated local counter is untouched.
+        #[inline]
+        fn reset(&self) {
+            self.global_counter.store(0, Ordering::SeqCst);
+        }
+    }
+
+    /// An eventually consistent counter.
+    ///
+    /// Like [ApproxCounter](struct.ApproxCounter.html), every thread increments its own local
+    /// counter. Unlike `ApproxCounter`, `get` never needs every thread to have manually called
+    /// `flush` to become exact: each thread lazily registers its local counter in a shared,
+    /// iterable [thread_local::ThreadLocal](https://docs.rs/thread_local), and `get` simply walks
+    /// every registered local, summing them. This turns the "flush from N threads" contract into
+    /// an automatic read-side sum, at the cost of `get` being `O(number of threads that have ever
+    /// called inc)` instead of `O(1)`.
+    ///
+    /// The total returned by `get` is exact modulo any increments that are concurrently
+    /// in-flight while it is summing.
+    ///
+    /// Note: because `ThreadLocal::new` allocates, this counter's `new` is, unlike most other
+    /// counters in this module, not usable in a `const` context.
+    #[cfg(not(no_std))]
+    pub struct EventuallyConsistentCounter {
+        start: usize,
+        locals: thread_local::ThreadLocal<AtomicUsize>,
+    }
+
+    #[cfg(not(no_std))]
+    impl EventuallyConsistentCounter {
+        /// Creates a new counter, with the given starting value.
+        #[inline]
+        pub fn new(start: usize) -> Self {
+            EventuallyConsistentCounter {
+                start,
+                locals: thread_local::ThreadLocal::new(),
+            }
+        }
+
+        /// Increments the counter by one.
+        #[inline]
+        pub fn inc(&self) {
+            self.locals
+                .get_or(|| AtomicUsize::new(0))
+                .fetch_add(1, Ordering::Relaxed);
+        }
+
+        /// Gets the current value of the counter, by summing every thread's local counter.
+        #[inline]
+        pub fn get(&self) -> usize {
+            self.start
+                + self
+                    .locals
+                    .iter()
+                    .map(|local| local.load(Ordering::Relaxed))
+                    .sum::<usize>()
+        }
+    }
+
+    #[cfg(not(no_std))]
+    impl crate::Counting for EventuallyConsistentCounter {
+        type Output = usize;
+
+        /// Increments this thread's local counter by one, then returns the summed `get` across
+        /// every thread - not just this thread's own previous value. Like
+        /// `FlushingCounter`/`ApproxCounter`'s `Counting::inc`, this is an exception to the rest
+        /// of this crate's "return the previous value" convention.
+        #[inline]
+        fn inc(&self) -> usize {
+            self.inc();
+            self.get()
+        }
+
+        /// Adds `n` to this thread's local counter directly, rather than looping `inc` `n` times.
+        #[inline]
+        fn add(&self, n: usize) -> usize {
+            self.locals
+                .get_or(|| AtomicUsize::new(0))
+                .fetch_add(n, Ordering::Relaxed);
+            self.get()
+        }
+
+        #[inline]
+        fn get(&self) -> usize {
+            self.get()
+        }
+
+        /// Zeroes every thread's already-registered local counter. Unlike
+        /// `FlushingCounter`/`ApproxCounter::reset`, the `start` offset is untouched, so `get`
+        /// returns `start` afterwards.
+        #[inline]
+        fn reset(&self) {
+            for local in self.locals.iter() {
+                local.store(0, Ordering::Relaxed);
+            }
+        }
+    }
+
     macro_rules! primitive_counter {
         ($( $primitive:ident $atomic:ident $counter:ident ), *) => {
             $(
@@ -232,23 +459,545 @@ pub mod primitive {
                     pub fn reset(&self){
                         self.0.store(0, match self.1{ Ordering::AcqRel => Ordering::Release, other => other });
                     }
+
+                    /// Gets the current value of the counter, using the given ordering instead of
+                    /// the one this counter was constructed with.
+                    ///
+                    /// Legal orderings for a load are `Relaxed`, `Acquire` and `SeqCst`; supplying
+                    /// `Release` or `AcqRel` is debug-asserted against, since neither is a legal
+                    /// ordering for a pure load.
+                    #[inline]
+                    pub fn get_with_ordering(&self, ordering: Ordering) -> $primitive{
+                        debug_assert!(
+                            !matches!(ordering, Ordering::Release | Ordering::AcqRel),
+                            "Release/AcqRel is not a legal ordering for a load"
+                        );
+                        self.0.load(ordering)
+                    }
+
+                    /// Sets the counter to a new value, using the given ordering instead of the
+                    /// one this counter was constructed with.
+                    ///
+                    /// Legal orderings for a store are `Relaxed`, `Release` and `SeqCst`; supplying
+                    /// `Acquire` or `AcqRel` is debug-asserted against, since neither is a legal
+                    /// ordering for a pure store.
+                    #[inline]
+                    pub fn set_with_ordering(&self, val: $primitive, ordering: Ordering){
+                        debug_assert!(
+                            !matches!(ordering, Ordering::Acquire | Ordering::AcqRel),
+                            "Acquire/AcqRel is not a legal ordering for a store"
+                        );
+                        self.0.store(val, ordering);
+                    }
+
+                    /// Increments the counter by one, returning the previous value, using the
+                    /// given ordering instead of the one this counter was constructed with.
+                    ///
+                    /// Every ordering is legal on this read-modify-write operation, so e.g.
+                    /// `Relaxed` can be used here to get a cheap event tally that doesn't pay for
+                    /// a full barrier, at the cost of the happens-before guarantee `SeqCst` gives.
+                    #[inline]
+                    pub fn inc_with_ordering(&self, ordering: Ordering) -> $primitive{
+                        self.0.fetch_add(1, ordering)
+                    }
+
+                    /// Resets the counter to zero, using the given ordering instead of the one
+                    /// this counter was constructed with.
+                    ///
+                    /// Legal orderings for a store are `Relaxed`, `Release` and `SeqCst`; supplying
+                    /// `Acquire` or `AcqRel` is debug-asserted against, since neither is a legal
+                    /// ordering for a pure store.
+                    #[inline]
+                    pub fn reset_with_ordering(&self, ordering: Ordering){
+                        debug_assert!(
+                            !matches!(ordering, Ordering::Acquire | Ordering::AcqRel),
+                            "Acquire/AcqRel is not a legal ordering for a store"
+                        );
+                        self.0.store(0, ordering);
+                    }
+                }
+
+                impl crate::Counting for $counter {
+                    type Output = $primitive;
+
+                    #[inline]
+                    fn inc(&self) -> $primitive {
+                        self.inc()
+                    }
+
+                    /// Increments the counter by `n`, returning the previous value. A single
+                    /// `fetch_add(n)`, far cheaper than calling `inc` `n` times.
+                    #[inline]
+                    fn add(&self, n: $primitive) -> $primitive {
+                        self.0.fetch_add(n, self.1)
+                    }
+
+                    #[inline]
+                    fn get(&self) -> $primitive {
+                        self.get()
+                    }
+
+                    #[inline]
+                    fn reset(&self) {
+                        self.reset()
+                    }
                 }
             )*
         };
     }
 
     primitive_counter![u8 AtomicU8 CounterU8, u16 AtomicU16 CounterU16, u32 AtomicU32 CounterU32, u64 AtomicU64 CounterU64, usize AtomicUsize CounterUsize, i8 AtomicI8 CounterI8, i16 AtomicI16 CounterI16, i32 AtomicI32 CounterI32, i64 AtomicI64 CounterI64, isize AtomicIsize CounterIsize];
+
+    macro_rules! primitive_counter_dec {
+        ($( $primitive:ident $counter:ident ), *) => {
+            $(
+                impl $counter {
+                    /// Decrements the counter by one, returning the previous value.
+                    #[inline]
+                    pub fn dec(&self) -> $primitive {
+                        self.0.fetch_sub(1, self.1)
+                    }
+
+                    /// Decrements the counter by `n`, returning the previous value.
+                    #[inline]
+                    pub fn sub(&self, n: $primitive) -> $primitive {
+                        self.0.fetch_sub(n, self.1)
+                    }
+                }
+            )*
+        };
+    }
+
+    // Only the signed counters and `CounterUsize` get `dec`/`sub`: subtracting from the
+    // remaining unsigned widths is too easy to silently wrap, and nobody has asked for it.
+    primitive_counter_dec![usize CounterUsize, i8 CounterI8, i16 CounterI16, i32 CounterI32, i64 CounterI64, isize CounterIsize];
+
+    /// A cache-line-padded wrapper, preventing false sharing between independently-written
+    /// atomics that would otherwise end up on the same cache line. Analogous to crossbeam-utils'
+    /// `CachePadded`.
+    #[repr(align(128))]
+    #[derive(Debug)]
+    #[cfg(not(no_std))]
+    struct CachePadded<T>(T);
+
+    #[cfg(not(no_std))]
+    impl<T> std::ops::Deref for CachePadded<T> {
+        type Target = T;
+
+        #[inline]
+        fn deref(&self) -> &T {
+            &self.0
+        }
+    }
+
+    /// Number of shards backing [StripedCounter](struct.StripedCounter.html).
+    ///
+    /// This is a fixed, compile-time constant rather than something picked from the CPU count at
+    /// construction time: building an array of non-`Copy` atomics of a runtime-chosen length
+    /// would need a heap allocation, which is at odds with `new` being usable in `static`
+    /// contexts the way most other counters in this module are. A power of two comfortably above
+    /// typical core counts is used instead.
+    #[cfg(not(no_std))]
+    const STRIPED_SHARDS: usize = 8;
+
+    #[cfg(not(no_std))]
+    static NEXT_SHARD_HINT: AtomicUsize = AtomicUsize::new(0);
+
+    /// A striped, cache-padded counter.
+    ///
+    /// The counters above all serialize every `inc` on a single `AtomicUsize`, so the cache line
+    /// backing it ping-pongs between cores under heavy contention, collapsing throughput.
+    /// `StripedCounter` instead spreads increments across `STRIPED_SHARDS` (8)
+    /// independent, cache-padded shards: each thread is lazily assigned one shard (via a cheap
+    /// thread-local hint) and only ever increments that shard, so two threads writing to
+    /// different shards never contend for the same cache line.
+    ///
+    /// This keeps `inc` wait-free. The trade-off shows up in `get`, which has to sum every shard,
+    /// and in the fact that a `get` running concurrently with increments only observes a
+    /// consistent-enough total, not a true snapshot at a single instant.
+    #[cfg(not(no_std))]
+    #[derive(Debug)]
+    pub struct StripedCounter {
+        shards: [CachePadded<AtomicUsize>; STRIPED_SHARDS],
+    }
+
+    #[cfg(not(no_std))]
+    impl StripedCounter {
+        /// Creates a new striped counter, with the given starting value. Can be used in static
+        /// contexts.
+        #[inline]
+        pub const fn new(start: usize) -> Self {
+            StripedCounter {
+                shards: [
+                    CachePadded(AtomicUsize::new(start)),
+                    CachePadded(AtomicUsize::new(0)),
+                    CachePadded(AtomicUsize::new(0)),
+                    CachePadded(AtomicUsize::new(0)),
+                    CachePadded(AtomicUsize::new(0)),
+                    CachePadded(AtomicUsize::new(0)),
+                    CachePadded(AtomicUsize::new(0)),
+                    CachePadded(AtomicUsize::new(0)),
+                ],
+            }
+        }
+
+        /// Increments the counter by one.
+        ///
+        /// This only ever touches the calling thread's shard, so it stays wait-free even with
+        /// many threads incrementing concurrently.
+        #[inline]
+        pub fn inc(&self) {
+            self.shards[self.shard_index()].fetch_add(1, Ordering::Relaxed);
+        }
+
+        /// Gets the current value of the counter, by summing every shard.
+        #[inline]
+        pub fn get(&self) -> usize {
+            self.shards
+                .iter()
+                .map(|shard| shard.load(Ordering::Acquire))
+                .sum()
+        }
+
+        /// Resets the counter to zero, by zeroing every shard.
+        #[inline]
+        pub fn reset(&self) {
+            for shard in self.shards.iter() {
+                shard.store(0, Ordering::Release);
+            }
+        }
+
+        /// Returns the shard this thread should use, lazily assigning one on first use.
+        #[inline]
+        fn shard_index(&self) -> usize {
+            thread_local! {
+                static SHARD_HINT: usize = NEXT_SHARD_HINT.fetch_add(1, Ordering::Relaxed);
+            }
+            SHARD_HINT.with(|hint| *hint) & (STRIPED_SHARDS - 1)
+        }
+    }
+
+    #[cfg(not(no_std))]
+    impl crate::Counting for StripedCounter {
+        type Output = usize;
+
+        #[inline]
+        fn inc(&self) -> usize {
+            self.inc();
+            self.get()
+        }
+
+        /// Adds `n` to the calling thread's own shard directly, rather than looping `inc` `n`
+        /// times.
+        #[inline]
+        fn add(&self, n: usize) -> usize {
+            self.shards[self.shard_index()].fetch_add(n, Ordering::Relaxed);
+            self.get()
+        }
+
+        #[inline]
+        fn get(&self) -> usize {
+            self.get()
+        }
+
+        #[inline]
+        fn reset(&self) {
+            self.reset()
+        }
+    }
+
+    /// Number of shards backing [ShardedCounter](struct.ShardedCounter.html). See the note on
+    /// `STRIPED_SHARDS` for why this is a fixed compile-time
+    /// constant rather than something chosen from the CPU count at construction time.
+    #[cfg(not(no_std))]
+    const SHARDED_SHARDS: usize = 16;
+
+    /// An exact, sharded counter.
+    ///
+    /// Like [StripedCounter](struct.StripedCounter.html), this keeps an array of cache-padded
+    /// atomics, so independent writer threads don't ping-pong the same cache line under heavy
+    /// contention. It differs in its shard count (`SHARDED_SHARDS`, 16, instead of 8) and in how
+    /// a thread picks its shard: instead of a cached thread-local index, `ShardedCounter` hashes
+    /// the calling thread's `std::thread::ThreadId` on every call.
+    ///
+    /// `get` sums every shard, so - unlike [ApproxCounter](struct.ApproxCounter.html) - there is
+    /// no per-thread accumulation error: once the increments have finished, `get` returns the
+    /// *exact* total. As with `StripedCounter`, a `get` running concurrently with increments is
+    /// not a snapshot at a single instant.
+    #[cfg(not(no_std))]
+    #[derive(Debug)]
+    pub struct ShardedCounter {
+        shards: [CachePadded<AtomicUsize>; SHARDED_SHARDS],
+    }
+
+    #[cfg(not(no_std))]
+    impl ShardedCounter {
+        /// Creates a new sharded counter, with the given starting value. Can be used in static
+        /// contexts.
+        ///
+        /// The shard count is fixed at `SHARDED_SHARDS` rather
+        /// than configurable per instance, for the same reason
+        /// [StripedCounter::new](struct.StripedCounter.html#method.new)'s is: building an array
+        /// of non-`Copy` atomics of an arbitrary, runtime-chosen length isn't possible in a
+        /// `const fn` without naming every element by hand.
+        #[inline]
+        pub const fn new(start: usize) -> Self {
+            ShardedCounter {
+                shards: [
+                    CachePadded(AtomicUsize::new(start)),
+                    CachePadded(AtomicUsize::new(0)),
+                    CachePadded(AtomicUsize::new(0)),
+                    CachePadded(AtomicUsize::new(0)),
+                    CachePadded(AtomicUsize::new(0)),
+                    CachePadded(AtomicUsize::new(0)),
+                    CachePadded(AtomicUsize::new(0)),
+                    CachePadded(AtomicUsize::new(0)),
+                    CachePadded(AtomicUsize::new(0)),
+                    CachePadded(AtomicUsize::new(0)),
+                    CachePadded(AtomicUsize::new(0)),
+                    CachePadded(AtomicUsize::new(0)),
+                    CachePadded(AtomicUsize::new(0)),
+                    CachePadded(AtomicUsize::new(0)),
+                    CachePadded(AtomicUsize::new(0)),
+                    CachePadded(AtomicUsize::new(0)),
+                ],
+            }
+        }
+
+        /// Increments the counter by one.
+        ///
+        /// This only ever touches the shard selected for the calling thread, so writers on
+        /// different shards never contend for the same cache line.
+        #[inline]
+        pub fn inc(&self) {
+            self.shards[self.shard_index()].fetch_add(1, Ordering::Relaxed);
+        }
+
+        /// Gets the current, exact value of the counter, by summing every shard.
+        #[inline]
+        pub fn get(&self) -> usize {
+            self.shards
+                .iter()
+                .map(|shard| shard.load(Ordering::Acquire))
+                .sum()
+        }
+
+        /// Resets the counter to zero, by zeroing every shard.
+        #[inline]
+        pub fn reset(&self) {
+            for shard in self.shards.iter() {
+                shard.store(0, Ordering::Release);
+            }
+        }
+
+        /// Picks a shard by hashing the calling thread's `ThreadId`.
+        #[inline]
+        fn shard_index(&self) -> usize {
+            use std::hash::{Hash, Hasher};
+
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            std::thread::current().id().hash(&mut hasher);
+            (hasher.finish() as usize) & (SHARDED_SHARDS - 1)
+        }
+    }
+
+    #[cfg(not(no_std))]
+    impl crate::Counting for ShardedCounter {
+        type Output = usize;
+
+        #[inline]
+        fn inc(&self) -> usize {
+            self.inc();
+            self.get()
+        }
+
+        /// Adds `n` to the calling thread's own shard directly, rather than looping `inc` `n`
+        /// times.
+        #[inline]
+        fn add(&self, n: usize) -> usize {
+            self.shards[self.shard_index()].fetch_add(n, Ordering::Relaxed);
+            self.get()
+        }
+
+        #[inline]
+        fn get(&self) -> usize {
+            self.get()
+        }
+
+        #[inline]
+        fn reset(&self) {
+            self.reset()
+        }
+    }
+
+    /// A waitable counter, usable as a lightweight barrier/latch.
+    ///
+    /// Besides the usual `inc`/`get`, this counter lets a thread block until the count reaches
+    /// or exceeds a target: `wait_until` registers the caller with a parker-based waiter
+    /// registry, then parks until `inc` observes the target has been met and unparks it.
+    ///
+    /// This supports "spawn N workers, main thread waits until all N have checked in" patterns
+    /// without an external channel or condvar.
+    #[cfg(not(no_std))]
+    pub struct WaitableCounter {
+        count: AtomicUsize,
+        waiters: std::sync::Mutex<Vec<(usize, usize, std::thread::Thread)>>,
+        next_waiter_id: AtomicUsize,
+    }
+
+    #[cfg(not(no_std))]
+    impl WaitableCounter {
+        /// Creates a new waitable counter, with the given starting value.
+        #[inline]
+        pub fn new(start: usize) -> WaitableCounter {
+            WaitableCounter {
+                count: AtomicUsize::new(start),
+                waiters: std::sync::Mutex::new(Vec::new()),
+                next_waiter_id: AtomicUsize::new(0),
+            }
+        }
+
+        /// Increments the counter by one, then unparks every waiter whose threshold is now met.
+        #[inline]
+        pub fn inc(&self) {
+            let new_val = self.count.fetch_add(1, Ordering::SeqCst) + 1;
+            self.unpark_ready(new_val);
+        }
+
+        /// Gets the current value of the counter.
+        #[inline]
+        pub fn get(&self) -> usize {
+            self.count.load(Ordering::SeqCst)
+        }
+
+        /// Blocks the calling thread until the counter's value is at least `target`.
+        ///
+        /// Registers the caller before re-checking the count, so an `inc` racing with this call
+        /// can never be missed: without the re-check, an `inc` landing between the caller's own
+        /// check and registration would satisfy `target` without ever unparking anyone, and the
+        /// caller would park forever.
+        pub fn wait_until(&self, target: usize) {
+            let _guard = self.register_waiter(target);
+
+            loop {
+                if self.get() >= target {
+                    return;
+                }
+                std::thread::park();
+            }
+        }
+
+        /// Like [wait_until](#method.wait_until), but gives up and returns `false` after
+        /// `timeout` instead of blocking forever. Returns `true` if `target` was reached.
+        pub fn wait_until_timeout(&self, target: usize, timeout: std::time::Duration) -> bool {
+            let _guard = self.register_waiter(target);
+            let deadline = std::time::Instant::now() + timeout;
+
+            loop {
+                if self.get() >= target {
+                    return true;
+                }
+                let now = std::time::Instant::now();
+                if now >= deadline {
+                    return false;
+                }
+                std::thread::park_timeout(deadline - now);
+            }
+        }
+
+        /// Registers the calling thread as a waiter for `target`, returning a guard that
+        /// deregisters it again on every return path - an immediate hit, a timed-out wait, or a
+        /// real wakeup alike - so a `WaitableCounter` reused as a repeated barrier never
+        /// accumulates waiters that `unpark_ready` has no reason to ever remove on its own.
+        fn register_waiter(&self, target: usize) -> WaiterGuard<'_> {
+            let id = self.next_waiter_id.fetch_add(1, Ordering::Relaxed);
+            self.waiters
+                .lock()
+                .expect("WaitableCounter waiter registry poisoned")
+                .push((id, target, std::thread::current()));
+            WaiterGuard { counter: self, id }
+        }
+
+        /// Unparks every registered waiter whose threshold `current` now satisfies. Waiters stay
+        /// registered until they deregister themselves via their `WaiterGuard`, so a waiter can
+        /// safely be unparked here more than once (e.g. a spurious wakeup re-parking) without
+        /// ever being dropped from the registry early.
+        fn unpark_ready(&self, current: usize) {
+            let waiters = self
+                .waiters
+                .lock()
+                .expect("WaitableCounter waiter registry poisoned");
+            for (_, threshold, thread) in waiters.iter() {
+                if current >= *threshold {
+                    thread.unpark();
+                }
+            }
+        }
+    }
+
+    #[cfg(not(no_std))]
+    impl crate::Counting for WaitableCounter {
+        type Output = usize;
+
+        #[inline]
+        fn inc(&self) -> usize {
+            self.inc();
+            self.get()
+        }
+
+        /// Adds `n` to the counter in one `fetch_add`, then unparks every waiter the bulk add
+        /// just satisfied, the same as `inc` does for a single step.
+        #[inline]
+        fn add(&self, n: usize) -> usize {
+            let new_val = self.count.fetch_add(n, Ordering::SeqCst) + n;
+            self.unpark_ready(new_val);
+            new_val
+        }
+
+        #[inline]
+        fn get(&self) -> usize {
+            self.get()
+        }
+
+        #[inline]
+        fn reset(&self) {
+            self.count.store(0, Ordering::SeqCst);
+        }
+    }
+
+    /// Deregisters a [WaitableCounter](struct.WaitableCounter.html) waiter on drop, so
+    /// `wait_until`/`wait_until_timeout` never leak a stale registry entry regardless of which
+    /// way they return.
+    #[cfg(not(no_std))]
+    struct WaiterGuard<'a> {
+        counter: &'a WaitableCounter,
+        id: usize,
+    }
+
+    #[cfg(not(no_std))]
+    impl Drop for WaiterGuard<'_> {
+        fn drop(&mut self) {
+            self.counter
+                .waiters
+                .lock()
+                .expect("WaitableCounter waiter registry poisoned")
+                .retain(|(id, _, _)| *id != self.id);
+        }
+    }
 }
 
 /// This module contains a generic, thread-safe counter and the accompanying `Inc` trait.
 pub mod generic {
 
-    #[cfg(parking_lot)]
+    #[cfg(all(parking_lot, not(no_std)))]
     use parking_lot::Mutex;
 
-    #[cfg(not(parking_lot))]
+    #[cfg(not(any(parking_lot, no_std)))]
     use std::sync::Mutex;
 
+    #[cfg(no_std)]
+    use spin::Mutex;
+
     /// This trait promises incrementing behaviour.
     /// Implemented for standard integer types.
     /// The current value is mutated, becoming the new, incremented value.
@@ -293,6 +1042,7 @@ pub mod generic {
     ///     assert_eq!(COUNTER_NAME.get_cloned(), 1);
     /// }
     /// ```
+    #[cfg(not(no_std))]
     #[macro_export]
     macro_rules! global_counter {
         ($name:ident, $type:ident, $value:expr) => {
@@ -318,6 +1068,7 @@ pub mod generic {
     ///     assert_eq!(COUNTER_NAME.get_cloned(), 1);
     /// }
     /// ```
+    #[cfg(not(no_std))]
     #[macro_export]
     macro_rules! global_default_counter {
         ($name:ident, $type:ty) => {
@@ -328,6 +1079,32 @@ pub mod generic {
         };
     }
 
+    /// `no_std` variant of [global_counter!](macro.global_counter.html).
+    ///
+    /// `lazy_static!` needs `std`, so under the `no_std` cfg the global is instead backed by a
+    /// `spin::Lazy` (itself a thin, `Deref`-friendly wrapper around a `spin::Once`, whose `new`
+    /// is `const`), giving the same call-site behaviour without requiring an allocator or OS
+    /// synchronization primitives.
+    #[cfg(no_std)]
+    #[macro_export]
+    macro_rules! global_counter {
+        ($name:ident, $type:ident, $value:expr) => {
+            static $name: spin::Lazy<global_counter::generic::Counter<$type>> =
+                spin::Lazy::new(|| global_counter::generic::Counter::new($value));
+        };
+    }
+
+    /// `no_std` variant of [global_default_counter!](macro.global_default_counter.html). See
+    /// [global_counter!](macro.global_counter.html) for why this differs from the `std` version.
+    #[cfg(no_std)]
+    #[macro_export]
+    macro_rules! global_default_counter {
+        ($name:ident, $type:ty) => {
+            static $name: spin::Lazy<global_counter::generic::Counter<$type>> =
+                spin::Lazy::new(global_counter::generic::Counter::default);
+        };
+    }
+
     impl<T: Inc> Counter<T> {
         /// Creates a new generic counter
         ///
@@ -414,68 +1191,684 @@ pub mod generic {
         /// });
         /// ```
         #[inline]
-        pub fn get_borrowed(&self) -> impl std::ops::Deref<Target = T> + '_ {
+        pub fn get_borrowed(&self) -> impl core::ops::Deref<Target = T> + '_ {
             self.lock()
         }
 
-        /// Returns a mutable borrow of the counted value, meaning the actual value counted by this counter can be mutated through this borrow.
-        ///
-        /// The constraints pointed out for [get_borrowed](struct.Counter.html#method.get_borrowed) also apply here.
-        ///
-        /// Although this API is in theory as safe as its immutable equivalent, usage of it is discouraged, as it is highly unidiomatic.
+        /// Returns a mutable borrow of the counted value, meaning the actual value counted by this counter can be mutated through this borrow.
+        ///
+        /// The constraints pointed out for [get_borrowed](struct.Counter.html#method.get_borrowed) also apply here.
+        ///
+        /// Although this API is in theory as safe as its immutable equivalent, usage of it is discouraged, as it is highly unidiomatic.
+        #[inline]
+        pub fn get_mut_borrowed(&self) -> impl core::ops::DerefMut<Target = T> + '_ {
+            self.lock()
+        }
+
+        /// Sets the counted value to the given value.
+        #[inline]
+        pub fn set(&self, val: T) {
+            *self.lock() = val;
+        }
+
+        /// Increments the counter, delegating the specific implementation to the [Inc](trait.Inc.html) trait.
+        #[inline]
+        pub fn inc(&self) {
+            self.lock().inc();
+        }
+
+        // `parking_lot::Mutex` and `spin::Mutex` both return the guard directly, with no
+        // poisoning to unwrap.
+        #[cfg(any(parking_lot, no_std))]
+        #[inline]
+        fn lock(&self) -> impl core::ops::DerefMut<Target = T> + '_ {
+            self.0.lock()
+        }
+
+        #[cfg(not(any(parking_lot, no_std)))]
+        #[inline]
+        fn lock(&self) -> impl core::ops::DerefMut<Target = T> + '_ {
+            self.0.lock().unwrap()
+        }
+    }
+
+    impl<T: Inc + Clone> Counter<T> {
+        /// This avoid the troubles of [get_borrowed](struct.Counter.html#method.get_borrowed) by cloning the current value.
+        ///
+        /// Creating a deadlock using this API should be impossible.
+        /// The downside of this approach is the cost of a forced clone which may, depending on your use case, not be affordable.
+        #[inline]
+        pub fn get_cloned(&self) -> T {
+            self.lock().clone()
+        }
+
+        /// Increments the counter, returning the previous value, cloned.
+        #[inline]
+        pub fn inc_cloning(&self) -> T {
+            let prev = self.get_cloned();
+            self.inc();
+            prev
+        }
+    }
+
+    impl<T: Inc + Default> Counter<T> {
+        /// Resets the counter to its default value.
+        #[inline]
+        pub fn reset(&self) {
+            self.set(T::default());
+        }
+    }
+
+    impl<T: Inc + Clone + Default + core::ops::AddAssign> crate::Counting for Counter<T> {
+        type Output = T;
+
+        #[inline]
+        fn inc(&self) -> T {
+            self.inc_cloning()
+        }
+
+        /// Adds `n` under a single lock acquisition, rather than looping `inc` `n` times. Needs
+        /// `T: AddAssign`, since `Inc` alone only knows how to add one.
+        #[inline]
+        fn add(&self, n: T) -> T {
+            let mut guard = self.lock();
+            let prev = guard.clone();
+            *guard += n;
+            prev
+        }
+
+        #[inline]
+        fn get(&self) -> T {
+            self.get_cloned()
+        }
+
+        #[inline]
+        fn reset(&self) {
+            Counter::reset(self)
+        }
+    }
+
+    use core::cell::UnsafeCell;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    /// A lock-free generic counter for small `Copy` types.
+    ///
+    /// [Counter](struct.Counter.html) always pays for a `Mutex`, even for types that would fit
+    /// in a machine word. `AtomicCounter` instead stores its value directly, guarded by a
+    /// seqlock-style version stamp (the same trick crossbeam's `AtomicCell` uses internally):
+    /// the stamp is bumped to odd before a write and back to even after, and readers spin,
+    /// re-reading the stamp, until they observe a stable even stamp around an unchanged value.
+    ///
+    /// This makes `get_cloned` non-blocking for readers racing a single in-flight write, and
+    /// lets `inc`/`set` serialize writers via the stamp itself (a compare-exchange loop) rather
+    /// than an OS mutex.
+    pub struct AtomicCounter<T: Inc + Copy> {
+        version: AtomicUsize,
+        value: UnsafeCell<T>,
+    }
+
+    // Safety: writers serialize on the version stamp (at most one mid-write at a time), and
+    // every access to `value`, racing or not, goes through `read_volatile`/`write_volatile`, so
+    // concurrent access from multiple threads is never a data race, even while a read and a
+    // write are in flight at the same time.
+    unsafe impl<T: Inc + Copy + Send> Sync for AtomicCounter<T> {}
+
+    impl<T: Inc + Copy> AtomicCounter<T> {
+        /// Creates a new atomic counter, with the given starting value.
+        #[inline]
+        pub fn new(val: T) -> AtomicCounter<T> {
+            AtomicCounter {
+                version: AtomicUsize::new(0),
+                value: UnsafeCell::new(val),
+            }
+        }
+
+        /// Returns a clone of the current value, spinning until a stable (even) version stamp is
+        /// observed around an unchanged value.
+        #[inline]
+        pub fn get_cloned(&self) -> T {
+            loop {
+                let before = self.version.load(Ordering::Acquire);
+                if before % 2 == 1 {
+                    continue;
+                }
+
+                // `read_volatile`, not a plain read: a writer may be racing this very access
+                // (it can only be caught, not prevented, by the stamp re-check below), and a
+                // plain load/store pair on the same location is a data race - instant UB, not
+                // just "maybe torn on this hardware". Volatile accesses are never UB to race,
+                // which is what lets the stamp dance above and below actually work as intended.
+                let val = unsafe { core::ptr::read_volatile(self.value.get()) };
+
+                let after = self.version.load(Ordering::Acquire);
+                if before == after {
+                    return val;
+                }
+            }
+        }
+
+        /// Sets the counter to a new value.
+        ///
+        /// Bumps the version stamp to odd before writing and back to even after, so concurrent
+        /// readers never observe a torn value, and so at most one writer is ever mid-write.
+        #[inline]
+        pub fn set(&self, val: T) {
+            self.fetch_update(|_| val);
+        }
+
+        /// Increments the counter, delegating to the [Inc](trait.Inc.html) impl of `T` on a
+        /// local copy, then storing the result.
+        ///
+        /// This is a CAS loop on the version stamp, not a plain read-then-write: if another
+        /// writer's update lands between our read and our claim on the stamp, we re-read and
+        /// retry from scratch, rather than overwriting with a value computed from a now-stale
+        /// read (which would silently lose that other writer's update).
+        #[inline]
+        pub fn inc(&self) {
+            self.fetch_update(|mut val| {
+                val.inc();
+                val
+            });
+        }
+
+        /// Atomically replaces the current value with `f(current)`, retrying the whole
+        /// read-modify-write if another writer's update is observed in between, and returns the
+        /// value that was replaced.
+        ///
+        /// `f` must be pure and cheap: it may be called more than once if this races with
+        /// another writer.
+        #[inline]
+        fn fetch_update(&self, f: impl Fn(T) -> T) -> T {
+            loop {
+                let before = self.version.load(Ordering::Acquire);
+                if before % 2 == 1 {
+                    continue;
+                }
+
+                // `read_volatile`: see the comment in `get_cloned` - a concurrent writer may be
+                // racing this load until we win the compare-exchange below, and plain accesses
+                // racing a write are UB regardless of what the stamp re-check would later catch.
+                let prev = unsafe { core::ptr::read_volatile(self.value.get()) };
+
+                if self
+                    .version
+                    .compare_exchange_weak(before, before + 1, Ordering::AcqRel, Ordering::Relaxed)
+                    .is_err()
+                {
+                    // Someone else claimed the stamp (or mutated it) since we read `prev`;
+                    // `prev` may already be stale, so retry the whole read-modify-write.
+                    continue;
+                }
+
+                // Safety: we just won the right to write by claiming the odd stamp. Still
+                // `write_volatile`, not a plain store: a reader may have already read the old
+                // even stamp and be about to read `value` right now, racing this very write.
+                unsafe {
+                    core::ptr::write_volatile(self.value.get(), f(prev));
+                }
+                self.version.fetch_add(1, Ordering::Release);
+                return prev;
+            }
+        }
+    }
+
+    impl<T: Inc + Copy + Default + core::ops::AddAssign> crate::Counting for AtomicCounter<T> {
+        type Output = T;
+
+        #[inline]
+        fn inc(&self) -> T {
+            self.fetch_update(|mut val| {
+                val.inc();
+                val
+            })
+        }
+
+        /// Adds `n` under the same CAS loop as `inc`, rather than looping `inc` `n` times. Needs
+        /// `T: AddAssign`, since `Inc` alone only knows how to add one - the same trade-off
+        /// [Counter](struct.Counter.html)'s `Counting` impl makes.
+        #[inline]
+        fn add(&self, n: T) -> T {
+            self.fetch_update(|mut val| {
+                val += n;
+                val
+            })
+        }
+
+        #[inline]
+        fn get(&self) -> T {
+            self.get_cloned()
+        }
+
+        #[inline]
+        fn reset(&self) {
+            self.set(T::default());
+        }
+    }
+
+    /// A spinlock, used internally by [LocklessCounter](struct.LocklessCounter.html) to guard
+    /// values that don't fit its lock-free fast path.
+    struct Spinlock(core::sync::atomic::AtomicBool);
+
+    impl Spinlock {
+        #[inline]
+        const fn new() -> Self {
+            Spinlock(core::sync::atomic::AtomicBool::new(false))
+        }
+
+        #[inline]
+        fn lock(&self) {
+            while self
+                .0
+                .compare_exchange_weak(
+                    false,
+                    true,
+                    Ordering::Acquire,
+                    Ordering::Relaxed,
+                )
+                .is_err()
+            {
+                while self.0.load(Ordering::Relaxed) {
+                    core::hint::spin_loop();
+                }
+            }
+        }
+
+        #[inline]
+        fn unlock(&self) {
+            self.0.store(false, Ordering::Release);
+        }
+    }
+
+    /// Number of spinlocks in the fallback lock table backing
+    /// [LocklessCounter](struct.LocklessCounter.html) for `T` that don't fit the lock-free fast
+    /// path. The table is shared across every such instance - rather than embedding a lock in
+    /// every cell, a cell is mapped to one of these shards by hashing its own address, the same
+    /// technique crossbeam's `AtomicCell` uses for its lock striping.
+    const LOCKLESS_LOCK_SHARDS: usize = 16;
+
+    static LOCKLESS_LOCKS: [Spinlock; LOCKLESS_LOCK_SHARDS] = [
+        Spinlock::new(),
+        Spinlock::new(),
+        Spinlock::new(),
+        Spinlock::new(),
+        Spinlock::new(),
+        Spinlock::new(),
+        Spinlock::new(),
+        Spinlock::new(),
+        Spinlock::new(),
+        Spinlock::new(),
+        Spinlock::new(),
+        Spinlock::new(),
+        Spinlock::new(),
+        Spinlock::new(),
+        Spinlock::new(),
+        Spinlock::new(),
+    ];
+
+    /// Picks this cell's fallback shard by hashing its own address.
+    #[inline]
+    fn lockless_shard_for<T>(ptr: *const T) -> &'static Spinlock {
+        // The lowest 3 bits of an address are often forced to zero by alignment, so they are
+        // shifted out; the next-lowest bits make a reasonable cheap hash on their own.
+        let idx = (ptr as usize >> 3) & (LOCKLESS_LOCK_SHARDS - 1);
+        &LOCKLESS_LOCKS[idx]
+    }
+
+    /// A counter for arbitrary `Copy` types, lock-free when `T`'s layout allows it.
+    ///
+    /// When `T`'s size exactly matches one of the machine's atomic widths (1, 2, 4 or 8 bytes)
+    /// and its alignment is at least that wide, `get`/`set`/`update` reinterpret the cell's
+    /// storage as the matching `core::sync::atomic` type and operate directly on `T`'s bit
+    /// pattern via load/store/compare-exchange - no locking at all, the same trick crossbeam's
+    /// `AtomicCell` uses. This requires `T` to have no uninitialized padding bits, which holds
+    /// for plain integers and `#[repr(C)]`/`#[repr(transparent)]` wrappers around them.
+    ///
+    /// For every other `T` (wrong size, or under-aligned for its size), this instead falls back
+    /// to a spinlock picked, by hashing the cell's address, from a small table shared across
+    /// every `LocklessCounter`, rather than growing every instance by
+    /// an embedded lock.
+    pub struct LocklessCounter<T: Copy> {
+        value: UnsafeCell<T>,
+    }
+
+    // Safety: on the fast path, `value` is only ever touched through the matching atomic type's
+    // own load/store/compare-exchange; on the fallback path, it is only read or written while
+    // `value`'s shard spinlock is held. Either way, concurrent access from multiple threads can
+    // never alias a mutable reference.
+    unsafe impl<T: Copy + Send> Sync for LocklessCounter<T> {}
+
+    impl<T: Copy> LocklessCounter<T> {
+        /// Creates a new lockless counter, with the given starting value.
+        #[inline]
+        pub const fn new(val: T) -> LocklessCounter<T> {
+            LocklessCounter {
+                value: UnsafeCell::new(val),
+            }
+        }
+
+        /// Whether `T`'s layout lets this counter take the lock-free fast path.
+        #[inline]
+        fn is_lock_free() -> bool {
+            let size = core::mem::size_of::<T>();
+            matches!(size, 1 | 2 | 4 | 8) && core::mem::align_of::<T>() >= size
+        }
+
+        /// Returns a copy of the current value.
+        #[inline]
+        pub fn get(&self) -> T {
+            if Self::is_lock_free() {
+                // Safety: `is_lock_free` confirmed `T`'s size and alignment match.
+                unsafe { Self::atomic_load(self.value.get()) }
+            } else {
+                let shard = lockless_shard_for(self.value.get());
+                shard.lock();
+                // Safety: the shard's spinlock is held.
+                let val = unsafe { *self.value.get() };
+                shard.unlock();
+                val
+            }
+        }
+
+        /// Sets the counter to a new value.
+        #[inline]
+        pub fn set(&self, val: T) {
+            if Self::is_lock_free() {
+                // Safety: `is_lock_free` confirmed `T`'s size and alignment match.
+                unsafe { Self::atomic_store(self.value.get(), val) };
+            } else {
+                let shard = lockless_shard_for(self.value.get());
+                shard.lock();
+                // Safety: the shard's spinlock is held.
+                unsafe {
+                    *self.value.get() = val;
+                }
+                shard.unlock();
+            }
+        }
+
+        /// Updates the counter by applying `f` to its current value, storing and returning the
+        /// result.
+        ///
+        /// On the fast path, this is a compare-exchange loop over `T`'s bit pattern, so `f` must
+        /// be pure and cheap: it may run more than once if another writer races it. On the
+        /// fallback path, `f` runs once, under the cell's shard spinlock, so no other reader or
+        /// writer can observe an intermediate state.
+        #[inline]
+        pub fn update(&self, f: impl Fn(T) -> T) -> T {
+            if Self::is_lock_free() {
+                loop {
+                    // Safety: `is_lock_free` confirmed `T`'s size and alignment match.
+                    let current = unsafe { Self::atomic_load(self.value.get()) };
+                    let new_val = f(current);
+                    // Safety: same as above.
+                    if unsafe { Self::atomic_cas(self.value.get(), current, new_val) } {
+                        return new_val;
+                    }
+                }
+            } else {
+                let shard = lockless_shard_for(self.value.get());
+                shard.lock();
+                // Safety: the shard's spinlock is held.
+                let new_val = unsafe {
+                    let slot = &mut *self.value.get();
+                    *slot = f(*slot);
+                    *slot
+                };
+                shard.unlock();
+                new_val
+            }
+        }
+
+        /// Loads `T`'s bit pattern through the atomic type matching its size.
+        ///
+        /// Safety: caller must have confirmed `Self::is_lock_free()`.
+        #[inline]
+        unsafe fn atomic_load(ptr: *mut T) -> T {
+            use core::sync::atomic::{AtomicU16, AtomicU32, AtomicU64, AtomicU8};
+            match core::mem::size_of::<T>() {
+                1 => core::mem::transmute_copy(&(*(ptr as *const AtomicU8)).load(Ordering::SeqCst)),
+                2 => {
+                    core::mem::transmute_copy(&(*(ptr as *const AtomicU16)).load(Ordering::SeqCst))
+                }
+                4 => {
+                    core::mem::transmute_copy(&(*(ptr as *const AtomicU32)).load(Ordering::SeqCst))
+                }
+                8 => {
+                    core::mem::transmute_copy(&(*(ptr as *const AtomicU64)).load(Ordering::SeqCst))
+                }
+                _ => unreachable!("is_lock_free() only allows sizes 1, 2, 4 or 8"),
+            }
+        }
+
+        /// Stores `val`'s bit pattern through the atomic type matching its size.
+        ///
+        /// Safety: caller must have confirmed `Self::is_lock_free()`.
+        #[inline]
+        unsafe fn atomic_store(ptr: *mut T, val: T) {
+            use core::sync::atomic::{AtomicU16, AtomicU32, AtomicU64, AtomicU8};
+            match core::mem::size_of::<T>() {
+                1 => (*(ptr as *const AtomicU8))
+                    .store(core::mem::transmute_copy(&val), Ordering::SeqCst),
+                2 => (*(ptr as *const AtomicU16))
+                    .store(core::mem::transmute_copy(&val), Ordering::SeqCst),
+                4 => (*(ptr as *const AtomicU32))
+                    .store(core::mem::transmute_copy(&val), Ordering::SeqCst),
+                8 => (*(ptr as *const AtomicU64))
+                    .store(core::mem::transmute_copy(&val), Ordering::SeqCst),
+                _ => unreachable!("is_lock_free() only allows sizes 1, 2, 4 or 8"),
+            }
+        }
+
+        /// Compare-exchanges `T`'s bit pattern through the atomic type matching its size.
+        /// Returns whether the exchange succeeded.
+        ///
+        /// Safety: caller must have confirmed `Self::is_lock_free()`.
+        #[inline]
+        unsafe fn atomic_cas(ptr: *mut T, current: T, new: T) -> bool {
+            use core::sync::atomic::{AtomicU16, AtomicU32, AtomicU64, AtomicU8};
+            match core::mem::size_of::<T>() {
+                1 => (*(ptr as *const AtomicU8))
+                    .compare_exchange_weak(
+                        core::mem::transmute_copy(&current),
+                        core::mem::transmute_copy(&new),
+                        Ordering::AcqRel,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok(),
+                2 => (*(ptr as *const AtomicU16))
+                    .compare_exchange_weak(
+                        core::mem::transmute_copy(&current),
+                        core::mem::transmute_copy(&new),
+                        Ordering::AcqRel,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok(),
+                4 => (*(ptr as *const AtomicU32))
+                    .compare_exchange_weak(
+                        core::mem::transmute_copy(&current),
+                        core::mem::transmute_copy(&new),
+                        Ordering::AcqRel,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok(),
+                8 => (*(ptr as *const AtomicU64))
+                    .compare_exchange_weak(
+                        core::mem::transmute_copy(&current),
+                        core::mem::transmute_copy(&new),
+                        Ordering::AcqRel,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok(),
+                _ => unreachable!("is_lock_free() only allows sizes 1, 2, 4 or 8"),
+            }
+        }
+    }
+
+    impl<T: Inc + Copy + Default + core::ops::AddAssign> crate::Counting for LocklessCounter<T> {
+        type Output = T;
+
+        /// Unlike the primitive counters' and the other generic counters' `Counting::inc`, this
+        /// returns the *new* value, not the previous one - matching `update`'s own existing
+        /// contract, which this delegates to directly.
+        #[inline]
+        fn inc(&self) -> T {
+            self.update(|mut val| {
+                val.inc();
+                val
+            })
+        }
+
+        /// Adds `n` under the same fast path (or fallback lock) as `update`, rather than
+        /// looping `inc` `n` times. Needs `T: AddAssign`, since `Inc` alone only knows how to
+        /// add one - the same trade-off `Counter<T>`'s `Counting` impl makes.
+        #[inline]
+        fn add(&self, n: T) -> T {
+            self.update(|mut val| {
+                val += n;
+                val
+            })
+        }
+
+        #[inline]
+        fn get(&self) -> T {
+            self.get()
+        }
+
+        #[inline]
+        fn reset(&self) {
+            self.set(T::default());
+        }
+    }
+
+    #[cfg(not(no_std))]
+    static NEXT_RW_SHARD_HINT: AtomicUsize = AtomicUsize::new(0);
+
+    /// Number of shards backing [ShardedRwCounter](struct.ShardedRwCounter.html).
+    #[cfg(not(no_std))]
+    const RW_SHARDS: usize = 8;
+
+    /// A generic counter optimized for many concurrent readers.
+    ///
+    /// [Counter::get_cloned](struct.Counter.html#method.get_cloned) contends on the same lock as
+    /// every writer. `ShardedRwCounter` instead keeps `RW_SHARDS` (8)
+    /// independent copies of `T`, each behind its own `RwLock` (the technique crossbeam-utils
+    /// uses for `ShardedLock`): a reader takes only its own shard's read guard (picked by a
+    /// cheap, lazily-assigned thread-local hint), so concurrent readers on different shards never
+    /// contend. A writer, in contrast, must take every shard's write guard, always in the same
+    /// (index) order, to keep every shard in sync and to avoid deadlocking against itself.
+    ///
+    /// This makes `get_cloned` cheap and parallel at the cost of a much more expensive `inc`/
+    /// `set` - the right trade-off for read-heavy global counters. Write-heavy users should keep
+    /// using the plain [Counter](struct.Counter.html).
+    #[cfg(not(no_std))]
+    pub struct ShardedRwCounter<T: Inc + Clone> {
+        shards: [std::sync::RwLock<T>; RW_SHARDS],
+    }
+
+    #[cfg(not(no_std))]
+    impl<T: Inc + Clone> ShardedRwCounter<T> {
+        /// Creates a new sharded counter, with the given starting value cloned into every shard.
         #[inline]
-        pub fn get_mut_borrowed(&self) -> impl std::ops::DerefMut<Target = T> + '_ {
-            self.lock()
+        pub fn new(val: T) -> ShardedRwCounter<T> {
+            ShardedRwCounter {
+                shards: [
+                    std::sync::RwLock::new(val.clone()),
+                    std::sync::RwLock::new(val.clone()),
+                    std::sync::RwLock::new(val.clone()),
+                    std::sync::RwLock::new(val.clone()),
+                    std::sync::RwLock::new(val.clone()),
+                    std::sync::RwLock::new(val.clone()),
+                    std::sync::RwLock::new(val.clone()),
+                    std::sync::RwLock::new(val),
+                ],
+            }
         }
 
-        /// Sets the counted value to the given value.
+        /// Returns a clone of the current value, taking only the calling thread's shard's read
+        /// lock, so readers on different shards never contend.
         #[inline]
-        pub fn set(&self, val: T) {
-            *self.lock() = val;
+        pub fn get_cloned(&self) -> T {
+            self.shards[self.shard_index()]
+                .read()
+                .expect("ShardedRwCounter shard poisoned")
+                .clone()
         }
 
-        /// Increments the counter, delegating the specific implementation to the [Inc](trait.Inc.html) trait.
+        /// Increments every shard, delegating to the [Inc](trait.Inc.html) impl of `T`.
+        ///
+        /// Takes every shard's write guard, in index order, before mutating any of them.
         #[inline]
         pub fn inc(&self) {
-            self.lock().inc();
+            let mut guards: Vec<_> = self
+                .shards
+                .iter()
+                .map(|shard| shard.write().expect("ShardedRwCounter shard poisoned"))
+                .collect();
+            for guard in guards.iter_mut() {
+                guard.inc();
+            }
         }
 
-        #[cfg(parking_lot)]
+        /// Sets every shard to a new value.
+        ///
+        /// Takes every shard's write guard, in index order, before mutating any of them.
         #[inline]
-        fn lock(&self) -> impl std::ops::DerefMut<Target = T> + '_ {
-            self.0.lock()
+        pub fn set(&self, val: T) {
+            let mut guards: Vec<_> = self
+                .shards
+                .iter()
+                .map(|shard| shard.write().expect("ShardedRwCounter shard poisoned"))
+                .collect();
+            for guard in guards.iter_mut() {
+                **guard = val.clone();
+            }
         }
 
-        #[cfg(not(parking_lot))]
+        /// Returns the shard this thread should read from, lazily assigning one on first use.
         #[inline]
-        fn lock(&self) -> impl std::ops::DerefMut<Target = T> + '_ {
-            self.0.lock().unwrap()
+        fn shard_index(&self) -> usize {
+            thread_local! {
+                static SHARD_HINT: usize = NEXT_RW_SHARD_HINT.fetch_add(1, Ordering::Relaxed);
+            }
+            SHARD_HINT.with(|hint| *hint) & (RW_SHARDS - 1)
         }
     }
 
-    impl<T: Inc + Clone> Counter<T> {
-        /// This avoid the troubles of [get_borrowed](struct.Counter.html#method.get_borrowed) by cloning the current value.
-        ///
-        /// Creating a deadlock using this API should be impossible.
-        /// The downside of this approach is the cost of a forced clone which may, depending on your use case, not be affordable.
-        #[inline]
-        pub fn get_cloned(&self) -> T {
-            self.lock().clone()
-        }
+    #[cfg(not(no_std))]
+    impl<T: Inc + Clone + Default + core::ops::AddAssign> crate::Counting for ShardedRwCounter<T> {
+        type Output = T;
 
-        /// Increments the counter, returning the previous value, cloned.
         #[inline]
-        pub fn inc_cloning(&self) -> T {
+        fn inc(&self) -> T {
             let prev = self.get_cloned();
             self.inc();
             prev
         }
-    }
 
-    impl<T: Inc + Default> Counter<T> {
-        /// Resets the counter to its default value.
+        /// Adds `n` under the same every-shard write-lock pass as `inc`/`set`, rather than
+        /// looping `inc` `n` times. Needs `T: AddAssign`, since `Inc` alone only knows how to
+        /// add one - the same trade-off `Counter<T>`'s `Counting` impl makes.
         #[inline]
-        pub fn reset(&self) {
+        fn add(&self, n: T) -> T {
+            let mut guards: Vec<_> = self
+                .shards
+                .iter()
+                .map(|shard| shard.write().expect("ShardedRwCounter shard poisoned"))
+                .collect();
+            let prev = guards[0].clone();
+            for guard in guards.iter_mut() {
+                **guard += n.clone();
+            }
+            prev
+        }
+
+        #[inline]
+        fn get(&self) -> T {
+            self.get_cloned()
+        }
+
+        #[inline]
+        fn reset(&self) {
             self.set(T::default());
         }
     }
@@ -487,7 +1880,9 @@ pub mod generic {
 // Should codecov be set up?
 // What about Travis? Necessary?
 
-#[cfg(test)]
+// These tests exercise the `std` surface of the crate and aren't run under the `no_std` cfg,
+// which drops several of the types used below.
+#[cfg(all(test, not(no_std)))]
 mod tests {
 
     #[cfg(test)]
@@ -800,10 +2195,234 @@ mod tests {
         }
     }
 
+    #[cfg(test)]
+    mod atomic_counter {
+        use crate::generic::AtomicCounter;
+
+        #[test]
+        fn count_to_five_single_threaded() {
+            let counter = AtomicCounter::new(0u32);
+            assert_eq!(counter.get_cloned(), 0);
+            counter.inc();
+            assert_eq!(counter.get_cloned(), 1);
+            counter.inc();
+            assert_eq!(counter.get_cloned(), 2);
+            counter.inc();
+            assert_eq!(counter.get_cloned(), 3);
+            counter.inc();
+            assert_eq!(counter.get_cloned(), 4);
+            counter.inc();
+            assert_eq!(counter.get_cloned(), 5);
+        }
+
+        #[test]
+        fn set_and_get() {
+            let counter = AtomicCounter::new(0u32);
+            counter.set(41);
+            assert_eq!(counter.get_cloned(), 41);
+        }
+
+        // This is a regression test for a lost-update race: the original inc() read via
+        // get_cloned(), mutated the copy, then called set(), with nothing re-validating that
+        // the read was still current - so concurrent incs could silently overwrite each other.
+        #[test]
+        fn count_to_50000_par_threaded() {
+            use std::sync::Arc;
+
+            let counter = Arc::new(AtomicCounter::new(0usize));
+
+            let threads: Vec<_> = (0..5)
+                .map(|_| {
+                    let counter = counter.clone();
+                    std::thread::spawn(move || {
+                        for _ in 0..10000 {
+                            counter.inc();
+                        }
+                    })
+                })
+                .collect();
+
+            for t in threads {
+                t.join().expect("Err joining thread");
+            }
+
+            assert_eq!(counter.get_cloned(), 50000);
+        }
+
+        #[test]
+        fn counting_inc_and_add() {
+            use crate::Counting;
+
+            let counter = AtomicCounter::new(0u32);
+            assert_eq!(Counting::inc(&counter), 0);
+            assert_eq!(Counting::get(&counter), 1);
+            assert_eq!(Counting::add(&counter, 4), 1);
+            assert_eq!(Counting::get(&counter), 5);
+            Counting::reset(&counter);
+            assert_eq!(Counting::get(&counter), 0);
+        }
+    }
+
+    #[cfg(test)]
+    mod lockless_counter {
+        use crate::generic::LocklessCounter;
+
+        #[test]
+        fn get_set_update() {
+            let counter = LocklessCounter::new(0u32);
+            assert_eq!(counter.get(), 0);
+            counter.set(41);
+            assert_eq!(counter.get(), 41);
+            assert_eq!(counter.update(|v| v + 1), 42);
+            assert_eq!(counter.get(), 42);
+        }
+
+        // A u32 takes the lock-free fast path (size 4, naturally aligned); this exercises it
+        // under contention.
+        #[test]
+        fn count_to_50000_par_threaded_fast_path() {
+            use std::sync::Arc;
+
+            let counter = Arc::new(LocklessCounter::new(0u32));
+
+            let threads: Vec<_> = (0..5)
+                .map(|_| {
+                    let counter = counter.clone();
+                    std::thread::spawn(move || {
+                        for _ in 0..10000 {
+                            counter.update(|v| v + 1);
+                        }
+                    })
+                })
+                .collect();
+
+            for t in threads {
+                t.join().expect("Err joining thread");
+            }
+
+            assert_eq!(counter.get(), 50000);
+        }
+
+        // A [u8; 3] doesn't match any atomic width, so this exercises the spinlock fallback.
+        #[test]
+        fn count_to_50000_par_threaded_fallback_path() {
+            use std::sync::Arc;
+
+            #[derive(Copy, Clone)]
+            struct Odd([u8; 3]);
+
+            fn to_u32(o: Odd) -> u32 {
+                u32::from_le_bytes([o.0[0], o.0[1], o.0[2], 0])
+            }
+
+            fn from_u32(n: u32) -> Odd {
+                let bytes = n.to_le_bytes();
+                Odd([bytes[0], bytes[1], bytes[2]])
+            }
+
+            let counter = Arc::new(LocklessCounter::new(from_u32(0)));
+
+            let threads: Vec<_> = (0..5)
+                .map(|_| {
+                    let counter = counter.clone();
+                    std::thread::spawn(move || {
+                        for _ in 0..10000 {
+                            counter.update(|v| from_u32(to_u32(v) + 1));
+                        }
+                    })
+                })
+                .collect();
+
+            for t in threads {
+                t.join().expect("Err joining thread");
+            }
+
+            assert_eq!(to_u32(counter.get()), 50000);
+        }
+
+        #[test]
+        fn counting_inc_and_add() {
+            use crate::Counting;
+
+            let counter = LocklessCounter::new(0u32);
+            assert_eq!(Counting::inc(&counter), 1);
+            assert_eq!(Counting::add(&counter, 4), 5);
+            assert_eq!(Counting::get(&counter), 5);
+            Counting::reset(&counter);
+            assert_eq!(Counting::get(&counter), 0);
+        }
+    }
+
+    #[cfg(test)]
+    mod sharded_rw_counter {
+        use crate::generic::ShardedRwCounter;
+
+        #[test]
+        fn count_to_five_single_threaded() {
+            let counter = ShardedRwCounter::new(0u32);
+            assert_eq!(counter.get_cloned(), 0);
+            counter.inc();
+            assert_eq!(counter.get_cloned(), 1);
+            counter.inc();
+            assert_eq!(counter.get_cloned(), 2);
+            counter.inc();
+            assert_eq!(counter.get_cloned(), 3);
+            counter.inc();
+            assert_eq!(counter.get_cloned(), 4);
+            counter.inc();
+            assert_eq!(counter.get_cloned(), 5);
+        }
+
+        #[test]
+        fn set_and_get() {
+            let counter = ShardedRwCounter::new(0u32);
+            counter.set(41);
+            assert_eq!(counter.get_cloned(), 41);
+        }
+
+        #[test]
+        fn count_to_50000_par_threaded() {
+            use std::sync::Arc;
+
+            let counter = Arc::new(ShardedRwCounter::new(0u32));
+
+            let threads: Vec<_> = (0..5)
+                .map(|_| {
+                    let counter = counter.clone();
+                    std::thread::spawn(move || {
+                        for _ in 0..10000 {
+                            counter.inc();
+                        }
+                    })
+                })
+                .collect();
+
+            for t in threads {
+                t.join().expect("Err joining thread");
+            }
+
+            assert_eq!(counter.get_cloned(), 50000);
+        }
+
+        #[test]
+        fn counting_inc_and_add() {
+            use crate::Counting;
+
+            let counter = ShardedRwCounter::new(0u32);
+            assert_eq!(Counting::inc(&counter), 0);
+            assert_eq!(Counting::get(&counter), 1);
+            assert_eq!(Counting::add(&counter, 4), 1);
+            assert_eq!(Counting::get(&counter), 5);
+            Counting::reset(&counter);
+            assert_eq!(Counting::get(&counter), 0);
+        }
+    }
+
     #[cfg(test)]
     mod primitive {
 
         use crate::primitive::*;
+        use std::sync::atomic::Ordering;
 
         #[test]
         fn approx_new_const() {
@@ -1028,7 +2647,17 @@ mod tests {
             assert_eq!(COUNTERISIZE.get(), 1);
         }
 
-        // FIXME: Add with_ordering test.
+        #[test]
+        fn with_ordering() {
+            static COUNTER: CounterU32 = CounterU32::new(0);
+            assert_eq!(COUNTER.get_with_ordering(Ordering::Relaxed), 0);
+            COUNTER.inc_with_ordering(Ordering::Relaxed);
+            assert_eq!(COUNTER.get_with_ordering(Ordering::Relaxed), 1);
+            COUNTER.set_with_ordering(41, Ordering::Relaxed);
+            assert_eq!(COUNTER.get_with_ordering(Ordering::SeqCst), 41);
+            COUNTER.reset_with_ordering(Ordering::SeqCst);
+            assert_eq!(COUNTER.get(), 0);
+        }
 
         #[test]
         fn primitive_reset() {
@@ -1223,5 +2852,245 @@ mod tests {
 
             assert_eq!(COUNTER.get(), 50000);
         }
+
+        #[test]
+        fn striped_new_const() {
+            static COUNTER: StripedCounter = StripedCounter::new(0);
+            assert_eq!(COUNTER.get(), 0);
+            COUNTER.inc();
+            assert_eq!(COUNTER.get(), 1);
+        }
+
+        #[test]
+        fn striped_reset() {
+            static COUNTER: StripedCounter = StripedCounter::new(0);
+            COUNTER.inc();
+            COUNTER.inc();
+            assert_eq!(COUNTER.get(), 2);
+            COUNTER.reset();
+            assert_eq!(COUNTER.get(), 0);
+        }
+
+        #[test]
+        fn striped_count_to_50000_par_threaded() {
+            static COUNTER: StripedCounter = StripedCounter::new(0);
+            assert_eq!(COUNTER.get(), 0);
+
+            let t_0 = std::thread::spawn(|| {
+                for _ in 0..10000 {
+                    COUNTER.inc();
+                }
+            });
+            let t_1 = std::thread::spawn(|| {
+                for _ in 0..10000 {
+                    COUNTER.inc();
+                }
+            });
+            let t_2 = std::thread::spawn(|| {
+                for _ in 0..10000 {
+                    COUNTER.inc();
+                }
+            });
+            let t_3 = std::thread::spawn(|| {
+                for _ in 0..10000 {
+                    COUNTER.inc();
+                }
+            });
+            let t_4 = std::thread::spawn(|| {
+                for _ in 0..10000 {
+                    COUNTER.inc();
+                }
+            });
+
+            t_0.join().expect("Err joining thread");
+            t_1.join().expect("Err joining thread");
+            t_2.join().expect("Err joining thread");
+            t_3.join().expect("Err joining thread");
+            t_4.join().expect("Err joining thread");
+
+            assert_eq!(COUNTER.get(), 50000);
+        }
+
+        #[test]
+        fn eventually_consistent_single_threaded() {
+            let counter = EventuallyConsistentCounter::new(0);
+            assert_eq!(counter.get(), 0);
+            counter.inc();
+            assert_eq!(counter.get(), 1);
+            counter.inc();
+            assert_eq!(counter.get(), 2);
+        }
+
+        #[test]
+        fn eventually_consistent_reset() {
+            let counter = EventuallyConsistentCounter::new(0);
+            counter.inc();
+            counter.inc();
+            assert_eq!(counter.get(), 2);
+
+            use crate::Counting;
+            Counting::reset(&counter);
+            assert_eq!(counter.get(), 0);
+        }
+
+        #[test]
+        fn eventually_consistent_count_to_50000_par_threaded() {
+            use std::sync::Arc;
+
+            let counter = Arc::new(EventuallyConsistentCounter::new(0));
+
+            let threads: Vec<_> = (0..5)
+                .map(|_| {
+                    let counter = counter.clone();
+                    std::thread::spawn(move || {
+                        for _ in 0..10000 {
+                            counter.inc();
+                        }
+                    })
+                })
+                .collect();
+
+            for t in threads {
+                t.join().expect("Err joining thread");
+            }
+
+            assert_eq!(counter.get(), 50000);
+        }
+
+        #[test]
+        fn sharded_new_const() {
+            static COUNTER: ShardedCounter = ShardedCounter::new(0);
+            assert_eq!(COUNTER.get(), 0);
+            COUNTER.inc();
+            assert_eq!(COUNTER.get(), 1);
+        }
+
+        #[test]
+        fn sharded_reset() {
+            static COUNTER: ShardedCounter = ShardedCounter::new(0);
+            COUNTER.inc();
+            COUNTER.inc();
+            assert_eq!(COUNTER.get(), 2);
+            COUNTER.reset();
+            assert_eq!(COUNTER.get(), 0);
+        }
+
+        #[test]
+        fn sharded_count_to_50000_par_threaded() {
+            static COUNTER: ShardedCounter = ShardedCounter::new(0);
+            assert_eq!(COUNTER.get(), 0);
+
+            let t_0 = std::thread::spawn(|| {
+                for _ in 0..10000 {
+                    COUNTER.inc();
+                }
+            });
+            let t_1 = std::thread::spawn(|| {
+                for _ in 0..10000 {
+                    COUNTER.inc();
+                }
+            });
+            let t_2 = std::thread::spawn(|| {
+                for _ in 0..10000 {
+                    COUNTER.inc();
+                }
+            });
+            let t_3 = std::thread::spawn(|| {
+                for _ in 0..10000 {
+                    COUNTER.inc();
+                }
+            });
+            let t_4 = std::thread::spawn(|| {
+                for _ in 0..10000 {
+                    COUNTER.inc();
+                }
+            });
+
+            t_0.join().expect("Err joining thread");
+            t_1.join().expect("Err joining thread");
+            t_2.join().expect("Err joining thread");
+            t_3.join().expect("Err joining thread");
+            t_4.join().expect("Err joining thread");
+
+            assert_eq!(COUNTER.get(), 50000);
+        }
+
+        #[test]
+        fn waitable_wait_until_already_met() {
+            let counter = WaitableCounter::new(5);
+            counter.wait_until(5);
+        }
+
+        #[test]
+        fn waitable_wait_until_blocks_then_unparks() {
+            use std::sync::Arc;
+
+            let counter = Arc::new(WaitableCounter::new(0));
+            let waiter_counter = counter.clone();
+            let waiter = std::thread::spawn(move || {
+                waiter_counter.wait_until(3);
+                assert!(waiter_counter.get() >= 3);
+            });
+
+            for _ in 0..3 {
+                counter.inc();
+            }
+
+            waiter.join().expect("Err joining thread");
+        }
+
+        #[test]
+        fn waitable_wait_until_timeout_expires() {
+            let counter = WaitableCounter::new(0);
+            let reached = counter.wait_until_timeout(1, std::time::Duration::from_millis(10));
+            assert!(!reached);
+        }
+
+        #[test]
+        fn waitable_wait_until_timeout_succeeds() {
+            let counter = WaitableCounter::new(0);
+            counter.inc();
+            let reached = counter.wait_until_timeout(1, std::time::Duration::from_secs(1));
+            assert!(reached);
+        }
+    }
+}
+
+// A minimal smoke test for the `no_std` surface: the `spin`-backed `generic::Counter` and the
+// `global_counter!`/`global_default_counter!` macros. The `std`-gated tests above don't run under
+// the `no_std` cfg, so without this, that whole surface would ship untested.
+#[cfg(all(test, no_std))]
+mod no_std_tests {
+    #[macro_use]
+    use crate::*;
+    use crate::generic::Counter;
+    use crate::Counting;
+
+    #[test]
+    fn count_to_five_single_threaded() {
+        let counter = Counter::new(0);
+        assert_eq!(*counter.get_borrowed(), 0);
+
+        for _ in 0..5 {
+            counter.inc();
+        }
+
+        assert_eq!(*counter.get_borrowed(), 5);
+    }
+
+    #[test]
+    fn global_counter_macro() {
+        global_counter!(COUNTER, u32, 0);
+        assert_eq!(*COUNTER.get_borrowed(), 0);
+        COUNTER.inc();
+        assert_eq!(*COUNTER.get_borrowed(), 1);
+    }
+
+    #[test]
+    fn global_default_counter_macro() {
+        global_default_counter!(COUNTER, u32);
+        assert_eq!(*COUNTER.get_borrowed(), 0);
+        COUNTER.add(41);
+        assert_eq!(*COUNTER.get_borrowed(), 41);
     }
 }